@@ -29,4 +29,106 @@ pub enum Error {
     #[error("Number is outside of range")]
     /// Number is outside of range
     OutOfRange,
+
+    #[error("Result is too large")]
+    /// Result of the arithmetic does not fit into the number
+    Overflow,
+
+    #[error("Number is not an integer")]
+    /// Number is not an integer
+    NotInteger,
+
+    #[error("Invalid digit for the given radix")]
+    /// Invalid digit for the given radix
+    InvalidDigit,
+
+    #[error("Lower bound is greater than upper bound")]
+    /// Lower bound is greater than upper bound
+    InvalidRange,
+
+    #[error("Invalid binary encoding of a number")]
+    /// Invalid binary encoding of a number
+    InvalidEncoding,
+
+    #[error("Not a number")]
+    /// Not a number
+    NaN,
+
+    #[error("Negative number")]
+    /// Negative number
+    NegativeNumber,
+
+    #[error("{context}: {error}")]
+    /// Another error with additional context, see `Error::with_context`
+    Context {
+        /// Description of what was being done
+        context: String,
+        /// The original error
+        error: Box<Error>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Coarse category of an `Error`
+pub enum ErrorKind {
+    /// The input could not be parsed
+    Syntax,
+    /// An argument is outside of the domain of the operation
+    Domain,
+    /// The arithmetic itself failed, e.g. division by zero or overflow
+    Arithmetic,
+}
+
+impl Error {
+    /// Get the category of the error, so callers can react without matching every variant
+    ///
+    /// ```
+    /// # use math::error::{Error, ErrorKind};
+    /// assert_eq!(Error::DivisionZero.kind(), ErrorKind::Arithmetic);
+    /// assert_eq!(Error::NegativeRoot.kind(), ErrorKind::Domain);
+    /// assert_eq!(Error::InvalidDigit.kind(), ErrorKind::Syntax);
+    ///
+    /// use math::Number;
+    /// assert_eq!(Number::from(u64::MAX).add(1).unwrap_err().kind(), ErrorKind::Arithmetic);
+    /// assert_eq!(Number::combination(3, 4).unwrap_err().kind(), ErrorKind::Domain);
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::DivisionZero | Error::Overflow | Error::NaN => ErrorKind::Arithmetic,
+            Error::FactorialNegative
+            | Error::LogNegativeBase
+            | Error::ZeroNthRoot
+            | Error::NegativeRoot
+            | Error::OutOfRange
+            | Error::NotInteger
+            | Error::InvalidRange
+            | Error::NegativeNumber => ErrorKind::Domain,
+            Error::InvalidDigit | Error::InvalidEncoding => ErrorKind::Syntax,
+            Error::Context { error, .. } => error.kind(),
+        }
+    }
+
+    /// Wrap the error with a description of what was being done when it happened.
+    /// The wrapped error keeps the `kind` of the original one
+    ///
+    /// ```
+    /// # use math::error::{Error, ErrorKind};
+    /// let err = Error::DivisionZero.with_context("cell A1");
+    /// assert_eq!(err.to_string(), "cell A1: Division zero");
+    /// assert_eq!(err.kind(), ErrorKind::Arithmetic);
+    ///
+    /// let err = err.with_context("sheet 2");
+    /// assert_eq!(err.to_string(), "sheet 2: cell A1: Division zero");
+    ///
+    /// // The original error is only part of the message, not a separate `source`
+    /// use std::error::Error as _;
+    /// assert!(err.source().is_none());
+    /// ```
+    pub fn with_context(self, context: impl Into<String>) -> Error {
+        Error::Context {
+            context: context.into(),
+            error: Box::new(self),
+        }
+    }
 }
@@ -0,0 +1,73 @@
+/// Locale specific rules for writing a `Number` in decimal, used by `Number::format_with`
+///
+/// ```
+/// # use math::Number;
+/// use math::format::NumberFormatter;
+///
+/// /// Swiss style: 1'234.5
+/// struct Swiss;
+///
+/// impl NumberFormatter for Swiss {
+///     fn decimal_separator(&self) -> char {
+///         '.'
+///     }
+///
+///     fn grouping_separator(&self) -> Option<char> {
+///         Some('\'')
+///     }
+///
+///     fn precision(&self) -> u8 {
+///         2
+///     }
+/// }
+///
+/// # fn main() -> math::Result<()> {
+///     assert_eq!(Number::new(-123456789, 1000)?.format_with(&Swiss), "-123'456.78");
+/// #     Ok(())
+/// # }
+/// ```
+pub trait NumberFormatter {
+    /// Character separating the integer and fractional part
+    fn decimal_separator(&self) -> char;
+
+    /// Character separating groups of integer digits, None to not group them
+    fn grouping_separator(&self) -> Option<char>;
+
+    /// Number of integer digits in a group
+    fn group_size(&self) -> usize {
+        3
+    }
+
+    /// Maximum number of fractional digits, the rest is truncated
+    fn precision(&self) -> u8 {
+        6
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+/// English (United States) formatting: 1,234,567.89 - default
+pub struct EnUsFormatter;
+
+impl NumberFormatter for EnUsFormatter {
+    fn decimal_separator(&self) -> char {
+        '.'
+    }
+
+    fn grouping_separator(&self) -> Option<char> {
+        Some(',')
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+/// German (Germany) formatting: 1.234.567,89
+pub struct DeDeFormatter;
+
+impl NumberFormatter for DeDeFormatter {
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn grouping_separator(&self) -> Option<char> {
+        Some('.')
+    }
+}
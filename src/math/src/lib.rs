@@ -6,6 +6,8 @@
 
 /// Error type
 pub mod error;
+/// Locale aware formatting
+pub mod format;
 /// Number type
 pub mod number;
 
@@ -14,14 +16,97 @@ pub use number::Number;
 /// Result type for this library
 pub type Result<T> = std::result::Result<T, error::Error>;
 
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
 /// Calculator struct
-pub struct Calculator;
+pub struct Calculator {
+    rng: StdRng,
+}
+
+impl Default for Calculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Calculator {
     /// Create a new instance
+    /// The random generator is seeded from the system entropy, use `set_seed` for reproducible results
     pub fn new() -> Self {
-        Self
+        Self {
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Reseed the random generator of this instance
+    /// The same seed always yields the same sequence of `random` and `randint` results
+    ///
+    /// ```
+    /// # use math::Calculator;
+    /// let mut a = Calculator::new();
+    /// let mut b = Calculator::new();
+    /// a.set_seed(42);
+    /// b.set_seed(42);
+    ///
+    /// for _ in 0..10 {
+    ///     assert_eq!(a.random(), b.random());
+    /// }
+    /// assert_eq!(a.randint(1, 6), b.randint(1, 6));
+    /// ```
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
     }
+
+    /// Generate a random number in range of <0, 1)
+    ///
+    /// ```
+    /// # use math::{Calculator, Number};
+    /// let mut calc = Calculator::new();
+    /// let x = calc.random();
+    /// assert!(x >= Number::ZERO);
+    /// assert!(x < Number::ONE);
+    /// ```
+    pub fn random(&mut self) -> Number {
+        let denom: u64 = self.rng.gen_range(1..(u64::MAX / 2));
+        let num: u64 = self.rng.gen_range(0..denom);
+
+        Number::new_unchecked(num as _, denom as _)
+    }
+
+    /// Generate a random integer in range of <lo, hi>
+    ///
+    /// # Error
+    /// Error::NotInteger if either `lo` or `hi` is not an integer
+    /// Error::InvalidRange if `lo` is greater than `hi`
+    ///
+    /// ```
+    /// # use math::{Calculator, Number};
+    /// use math::error::Error;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let mut calc = Calculator::new();
+    ///     assert!(calc.randint(Number::new(1, 2)?, 3).is_err());
+    ///     assert_eq!(calc.randint(3, 1), Err(Error::InvalidRange));
+    ///     assert_eq!(calc.randint(4, 4)?, Number::from(4));
+    ///
+    ///     let x = calc.randint(-3, 3)?;
+    ///     assert!(x.is_integer());
+    ///     assert!(x >= Number::from(-3) && x <= Number::from(3));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn randint(&mut self, lo: impl Into<Number>, hi: impl Into<Number>) -> Result<Number> {
+        let lo = lo.into().to_i128()?;
+        let hi = hi.into().to_i128()?;
+
+        if lo > hi {
+            return Err(error::Error::InvalidRange);
+        }
+
+        Ok(Number::from(self.rng.gen_range(lo..=hi)))
+    }
+
     /// Evaluate the infix math expression
     pub fn evaluate(&mut self, _s: &str) -> Result<Number> {
         todo!();
@@ -1,7 +1,9 @@
 use crate::error::Error;
+use crate::format::NumberFormatter;
 use crate::Result;
-use fraction::GenericFraction;
+use fraction::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, GenericFraction, ToPrimitive};
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 
 #[derive(Default, Debug, Clone, Copy)]
 /// Represent a number
@@ -9,6 +11,14 @@ pub struct Number {
     inner: GenericFraction<u64>,
 }
 
+/// Format version written by `Number::to_bytes`
+const BYTES_VERSION: u8 = 1;
+const BYTES_TAG_POSITIVE: u8 = 0;
+const BYTES_TAG_NEGATIVE: u8 = 1;
+const BYTES_TAG_INFINITY: u8 = 2;
+const BYTES_TAG_NEG_INFINITY: u8 = 3;
+const BYTES_TAG_NAN: u8 = 4;
+
 #[derive(Default, Debug, Clone, Copy)]
 #[non_exhaustive]
 /// Radix to use to represent a Number
@@ -24,6 +34,37 @@ pub enum Radix {
     Hex,
 }
 
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// How to round a number which lies between two candidates
+pub enum RoundingMode {
+    #[default]
+    /// Round to the nearest, ties away from zero - default
+    HalfAwayFromZero,
+    /// Round to the nearest, ties toward positive infinity
+    HalfUp,
+    /// Round to the nearest, ties to the even candidate (banker's rounding)
+    HalfEven,
+    /// Always round toward zero
+    TowardZero,
+    /// Always round toward negative infinity
+    Floor,
+    /// Always round toward positive infinity
+    Ceiling,
+}
+
+impl Radix {
+    /// Get the base of the radix
+    fn base(self) -> u128 {
+        match self {
+            Radix::Bin => 2,
+            Radix::Oct => 8,
+            Radix::Dec => 10,
+            Radix::Hex => 16,
+        }
+    }
+}
+
 impl<T: Into<GenericFraction<u64>>> From<T> for Number {
     fn from(v: T) -> Self {
         Self { inner: v.into() }
@@ -70,7 +111,7 @@ impl Number {
         let n = num.abs() as u64;
         let d = denom.abs() as u64;
 
-        let sign = if num.is_positive() == denom.is_positive() {
+        let sign = if num.is_negative() == denom.is_negative() {
             fraction::Sign::Plus
         } else {
             fraction::Sign::Minus
@@ -81,13 +122,81 @@ impl Number {
         }
     }
 
+    /// Parse a number written in the given `radix`, e.g. `"-ff"` in base 16 or `"10.1"` in base 2.
+    /// A leading `+` or `-` sign and a single `.` separating the fractional part are accepted
+    ///
+    /// # Error
+    /// Error::OutOfRange if `radix` is not in the range <2, 36> or the value does not fit
+    /// Error::InvalidDigit if `s` is empty or contains a digit not valid for `radix`
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::from_str_radix("ff", 16)?, Number::from(255));
+    ///     assert_eq!(Number::from_str_radix("-1A", 16)?, Number::from(-26));
+    ///     assert_eq!(Number::from_str_radix("101", 2)?, Number::from(5));
+    ///     assert_eq!(Number::from_str_radix("+10.1", 2)?, Number::new(5, 2)?);
+    ///     assert_eq!(Number::from_str_radix(".8", 16)?, Number::new(1, 2)?);
+    ///     assert_eq!(Number::from_str_radix("0.50000000000000000000", 10)?, Number::new(1, 2)?);
+    ///     assert_eq!(Number::from_str_radix("-3.000000000000000000000000", 10)?, Number::from(-3));
+    ///
+    ///     assert!(Number::from_str_radix("102", 2).is_err());
+    ///     assert!(Number::from_str_radix("fg", 16).is_err());
+    ///     assert!(Number::from_str_radix("-", 10).is_err());
+    ///     assert!(Number::from_str_radix("1.2.3", 10).is_err());
+    ///     assert!(Number::from_str_radix("1", 37).is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self> {
+        if !(2..=36).contains(&radix) {
+            return Err(Error::OutOfRange);
+        }
+
+        let (sign, digits) = match s.as_bytes().first() {
+            Some(b'-') => (fraction::Sign::Minus, &s[1..]),
+            Some(b'+') => (fraction::Sign::Plus, &s[1..]),
+            _ => (fraction::Sign::Plus, s),
+        };
+
+        let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(Error::InvalidDigit);
+        }
+        // Trailing zeros do not change the value, but would overflow `num` and `denom`
+        let frac_part = frac_part.trim_end_matches('0');
+
+        let radix64 = u64::from(radix);
+        let mut num: u64 = 0;
+        for c in int_part.chars().chain(frac_part.chars()) {
+            let digit = c.to_digit(radix).ok_or(Error::InvalidDigit)?;
+            num = num
+                .checked_mul(radix64)
+                .and_then(|n| n.checked_add(u64::from(digit)))
+                .ok_or(Error::OutOfRange)?;
+        }
+
+        let mut denom: u64 = 1;
+        for _ in frac_part.chars() {
+            denom = denom.checked_mul(radix64).ok_or(Error::OutOfRange)?;
+        }
+
+        let inner = match sign {
+            fraction::Sign::Plus => GenericFraction::new(num, denom),
+            fraction::Sign::Minus => GenericFraction::new_neg(num, denom),
+        };
+
+        Ok(Self { inner })
+    }
+
     /// Get the formatted string of a number
     ///
     /// ```
     /// # use math::number::{Number, Radix};
-    /// let pi = Numper::PI;
+    /// let pi = Number::PI;
     /// let zero = Number::ZERO;
-    /// let neg = Number::new(-1, 10);
+    /// let neg = Number::new(-1, 10).unwrap();
     ///
     /// let precision = 6;
     ///
@@ -107,175 +216,1464 @@ impl Number {
     /// assert_eq!(neg.to_string(Radix::Hex, precision), "-0.199999");
     /// ```
     pub fn to_string(&self, radix: Radix, precision: u8) -> String {
-        todo!()
+        let Some((negative, int, frac)) = self.digits(radix, precision) else {
+            return self.non_finite_string();
+        };
+
+        let sign = if negative { "-" } else { "" };
+        if frac.is_empty() {
+            format!("{sign}{int}")
+        } else {
+            format!("{sign}{int}.{frac}")
+        }
     }
-}
 
-impl Number {
-    /// Generate a random number in range of <0, 1>
+    /// Format the number using the separators and precision of the given formatter
     ///
     /// ```
     /// # use math::Number;
-    /// assert!(Number::random() != Number::random());
-    /// assert!(Number::random() >= Number::ZERO);
-    /// assert!(Number::random() <= Number::ONE);
+    /// use math::format::{DeDeFormatter, EnUsFormatter};
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let x = Number::new(123456789, 100)?;
+    ///     assert_eq!(x.format_with(&EnUsFormatter), "1,234,567.89");
+    ///     assert_eq!(x.format_with(&DeDeFormatter), "1.234.567,89");
+    ///
+    ///     assert_eq!(Number::from(-1000).format_with(&EnUsFormatter), "-1,000");
+    ///     assert_eq!(Number::from(999).format_with(&DeDeFormatter), "999");
+    ///     assert_eq!(Number::new(-1, 3)?.format_with(&DeDeFormatter), "-0,333333");
+    /// #     Ok(())
+    /// # }
     /// ```
-    pub fn random() -> Self {
-        use rand::prelude::*;
+    pub fn format_with(&self, formatter: &dyn NumberFormatter) -> String {
+        let Some((negative, int, frac)) = self.digits(Radix::Dec, formatter.precision()) else {
+            return self.non_finite_string();
+        };
 
-        let mut rng = rand::thread_rng();
-        let denom: u64 = rng.gen_range(1..(u64::MAX / 2));
-        let num: u64 = rng.gen_range(0..=denom);
+        let mut out = String::new();
+        if negative {
+            out.push('-');
+        }
 
-        Self::new_unchecked(num as _, denom as _)
+        let group = formatter.group_size().max(1);
+        for (i, c) in int.chars().enumerate() {
+            if i > 0 && (int.len() - i) % group == 0 {
+                if let Some(separator) = formatter.grouping_separator() {
+                    out.push(separator);
+                }
+            }
+            out.push(c);
+        }
+
+        if !frac.is_empty() {
+            out.push(formatter.decimal_separator());
+            out.push_str(&frac);
+        }
+
+        out
     }
 
-    /// Add two numbers together
+    /// Get the number formatted as a percentage with at most `digits` fractional digits,
+    /// e.g. `0.75` is `"75%"`
     ///
     /// ```
     /// # use math::Number;
     ///
     /// # fn main() -> math::Result<()> {
-    /// let a = Number::new(1, 10)?;
-    /// let b = Number::new(2, 10)?;
-    ///
-    /// assert_eq!(a.add(b)?, Number::new(3, 10)?);
-    /// assert_eq!(b.add(a), a.add(b));
+    ///     assert_eq!(Number::new(1, 2)?.to_percent_string(0), "50%");
+    ///     assert_eq!(Number::new(333, 1000)?.to_percent_string(1), "33.3%");
+    ///     assert_eq!(Number::new(1, 3)?.to_percent_string(2), "33.33%");
+    ///     assert_eq!(Number::from(2).to_percent_string(2), "200%");
+    ///     assert_eq!(Number::new(-1, 400)?.to_percent_string(2), "-0.25%");
+    ///     assert_eq!(Number::new(-1, 400)?.to_percent_string(0), "0%");
+    ///     assert_eq!(Number::from(u64::MAX).to_percent_string(0), "1844674407370955161500%");
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn add(&self, other: impl Into<Self>) -> Result<Self> {
-        Ok(Self {
-            inner: self.inner + other.into().inner,
-        })
+    pub fn to_percent_string(&self, digits: usize) -> String {
+        // Shift the decimal point of the digits instead of multiplying, so it cannot overflow
+        let precision = u8::try_from(digits.saturating_add(2)).unwrap_or(u8::MAX);
+        let Some((negative, int, frac)) = self.digits(Radix::Dec, precision) else {
+            return format!("{}%", self.non_finite_string());
+        };
+
+        let frac = format!("{frac:0<2}");
+        let (shifted, frac) = frac.split_at(2);
+        let int = format!("{int}{shifted}");
+        let int = match int.trim_start_matches('0') {
+            "" => "0",
+            int => int,
+        };
+        let frac = frac.trim_end_matches('0');
+
+        let sign = if negative { "-" } else { "" };
+        if frac.is_empty() {
+            format!("{sign}{int}%")
+        } else {
+            format!("{sign}{int}.{frac}%")
+        }
     }
 
-    /// Subtract two numbers
+    /// Expand the number into its sign, integer digits and fractional digits in the given `radix`.
+    /// The fractional part is truncated to `precision` digits and stripped of trailing zeros,
+    /// the sign is only set if any of the digits is not zero
+    ///
+    /// Return None if the number is not finite
+    pub(crate) fn digits(&self, radix: Radix, precision: u8) -> Option<(bool, String, String)> {
+        let GenericFraction::Rational(sign, ratio) = self.inner else {
+            return None;
+        };
+
+        let base = radix.base();
+        let num = u128::from(*ratio.numer());
+        let denom = u128::from(*ratio.denom());
+
+        let mut int = num / denom;
+        let mut int_digits = Vec::new();
+        loop {
+            int_digits.push(digit_char(int % base, base));
+            int /= base;
+            if int == 0 {
+                break;
+            }
+        }
+        let int_digits: String = int_digits.into_iter().rev().collect();
+
+        let mut rem = num % denom;
+        let mut frac_digits = String::new();
+        for _ in 0..precision {
+            if rem == 0 {
+                break;
+            }
+            rem *= base;
+            frac_digits.push(digit_char(rem / denom, base));
+            rem %= denom;
+        }
+        let frac_digits = frac_digits.trim_end_matches('0').to_owned();
+
+        let is_zero = int_digits == "0" && frac_digits.is_empty();
+        let negative = sign == fraction::Sign::Minus && !is_zero;
+
+        Some((negative, int_digits, frac_digits))
+    }
+
+    /// Get the string of a number which is either not a number or infinite
+    fn non_finite_string(&self) -> String {
+        match self.inner {
+            GenericFraction::Infinity(fraction::Sign::Plus) => "inf".to_owned(),
+            GenericFraction::Infinity(fraction::Sign::Minus) => "-inf".to_owned(),
+            _ => "NaN".to_owned(),
+        }
+    }
+
+    /// Encode the number into a compact binary form, see `Number::from_bytes`
+    ///
+    /// The first byte is the format version, the second one tags the kind of number.
+    /// A finite number is followed by its numerator and denominator as little-endian `u64`
     ///
     /// ```
     /// # use math::Number;
     ///
     /// # fn main() -> math::Result<()> {
-    ///     let a = Number::new(1, 10)?;
-    ///     let b = Number::new(2, 10)?;
+    ///     let values = [
+    ///         Number::ZERO,
+    ///         Number::from(42),
+    ///         Number::from(-7),
+    ///         Number::new(-1, 3)?,
+    ///         Number::from(u64::MAX),
+    ///         Number::new(1, i64::MAX)?,
+    ///         Number::from(f64::INFINITY),
+    ///         Number::from(f64::NEG_INFINITY),
+    ///         Number::from(f64::NAN),
+    ///     ];
+    ///
+    ///     for value in values {
+    ///         assert_eq!(Number::from_bytes(&value.to_bytes())?, value);
+    ///     }
+    ///     assert_eq!(Number::from(42).to_bytes().len(), 18);
+    ///     assert_eq!(Number::from(f64::NAN).to_bytes().len(), 2);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![BYTES_VERSION];
+
+        match self.inner {
+            GenericFraction::Rational(sign, ratio) => {
+                bytes.push(match sign {
+                    fraction::Sign::Plus => BYTES_TAG_POSITIVE,
+                    fraction::Sign::Minus => BYTES_TAG_NEGATIVE,
+                });
+                bytes.extend_from_slice(&ratio.numer().to_le_bytes());
+                bytes.extend_from_slice(&ratio.denom().to_le_bytes());
+            }
+            GenericFraction::Infinity(fraction::Sign::Plus) => bytes.push(BYTES_TAG_INFINITY),
+            GenericFraction::Infinity(fraction::Sign::Minus) => bytes.push(BYTES_TAG_NEG_INFINITY),
+            GenericFraction::NaN => bytes.push(BYTES_TAG_NAN),
+        }
+
+        bytes
+    }
+
+    /// Decode a number encoded by `Number::to_bytes`
     ///
-    ///     assert_eq!(a.sub(b)?, Number::new(-1, 10)?);
-    ///     assert_eq!(b.sub(a)?, Number::new(1, 10)?);
+    /// # Error
+    /// Error::InvalidEncoding if the version or tag is unknown or the length does not match
+    /// Error::DivisionZero if the encoded denominator is 0
+    ///
+    /// ```
+    /// # use math::Number;
+    /// assert!(Number::from_bytes(&[]).is_err());
+    /// assert!(Number::from_bytes(&[0xFF, 0]).is_err());
+    /// assert!(Number::from_bytes(&Number::ONE.to_bytes()[..10]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (tag, payload) = match bytes {
+            [BYTES_VERSION, tag, payload @ ..] => (*tag, payload),
+            _ => return Err(Error::InvalidEncoding),
+        };
+
+        let inner = match (tag, payload.len()) {
+            (BYTES_TAG_POSITIVE | BYTES_TAG_NEGATIVE, 16) => {
+                let (num, denom) = payload.split_at(8);
+                let num = u64::from_le_bytes(num.try_into().map_err(|_| Error::InvalidEncoding)?);
+                let denom =
+                    u64::from_le_bytes(denom.try_into().map_err(|_| Error::InvalidEncoding)?);
+
+                if denom == 0 {
+                    return Err(Error::DivisionZero);
+                }
+
+                let sign = if tag == BYTES_TAG_NEGATIVE {
+                    fraction::Sign::Minus
+                } else {
+                    fraction::Sign::Plus
+                };
+                GenericFraction::new_raw_signed(sign, num, denom)
+            }
+            (BYTES_TAG_INFINITY, 0) => GenericFraction::infinity(),
+            (BYTES_TAG_NEG_INFINITY, 0) => GenericFraction::neg_infinity(),
+            (BYTES_TAG_NAN, 0) => GenericFraction::nan(),
+            _ => return Err(Error::InvalidEncoding),
+        };
+
+        Ok(Self { inner })
+    }
+
+    /// Check whether the number is zero, `-0` included
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert!(Number::ZERO.is_zero());
+    ///     assert!(Number::from(0).is_zero());
+    ///     assert!(Number::from(-0.0).is_zero());
+    ///     assert!(Number::new(0, 5)?.is_zero());
+    ///     assert!(Number::new(0, -5)?.is_zero());
+    ///     assert!(!Number::new(1, 1000)?.is_zero());
+    ///     assert!(!Number::from(f64::NAN).is_zero());
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn sub(&self, other: impl Into<Self>) -> Result<Self> {
-        Ok(Self {
-            inner: self.inner - other.into().inner,
-        })
+    pub fn is_zero(&self) -> bool {
+        self.inner.numer().is_some_and(|n| *n == 0)
     }
 
-    /// Multiply two numbers
+    /// Check whether the number is exactly one
     ///
     /// ```
     /// # use math::Number;
     ///
     /// # fn main() -> math::Result<()> {
-    ///     let a = Number::new(1, 10)?;
-    ///     let b = Number::new(2, 10)?;
+    ///     assert!(Number::ONE.is_one());
+    ///     assert!(Number::from(1).is_one());
+    ///     assert!(Number::from(1.0).is_one());
+    ///     assert!(Number::new(5, 5)?.is_one());
+    ///     assert!(Number::new(-5, -5)?.is_one());
+    ///     assert!(!Number::from(-1).is_one());
+    ///     assert!(!Number::new(1, 5)?.is_one());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn is_one(&self) -> bool {
+        *self == Self::ONE
+    }
+
+    /// Check whether two numbers are equal within `max_ulps` units in the last place
+    /// of their `f64` approximations, a tolerance independent of the magnitude.
+    /// Numbers which are exactly equal always compare equal, NaN never does
     ///
-    ///     assert_eq!(a.mul(b)?, Number::new(2, 100)?);
-    ///     assert_eq!(b.mul(a), a.mul(b));
+    /// ```
+    /// # use math::Number;
+    /// let one = 1.0_f64;
+    /// let next = f64::from_bits(one.to_bits() + 1);
+    /// assert!(Number::from(one).approx_eq_ulps(Number::from(next), 1));
+    /// assert!(!Number::from(one).approx_eq_ulps(Number::from(next), 0));
+    ///
+    /// let big = 1e15_f64;
+    /// let next = f64::from_bits(big.to_bits() + 2);
+    /// assert!(Number::from(big).approx_eq_ulps(Number::from(next), 2));
+    ///
+    /// assert!(Number::from(-3).approx_eq_ulps(Number::from(-3), 0));
+    /// assert!(!Number::from(1).approx_eq_ulps(Number::from(1.001), 1000));
+    /// assert!(!Number::from(1).approx_eq_ulps(Number::from(-1), u32::MAX));
+    /// assert!(!Number::from(f64::NAN).approx_eq_ulps(Number::from(f64::NAN), u32::MAX));
+    /// ```
+    pub fn approx_eq_ulps(&self, other: impl Into<Self>, max_ulps: u32) -> bool {
+        let other = other.into();
+
+        if self.is_nan() || other.is_nan() {
+            return false;
+        }
+        if *self == other {
+            return true;
+        }
+
+        // Map the bits onto a line where neighbouring floats differ by one
+        let ordered = |x: f64| {
+            let bits = x.to_bits() as i64;
+            if bits < 0 {
+                i64::MIN - bits
+            } else {
+                bits
+            }
+        };
+
+        let distance = (i128::from(ordered(self.to_f64())) - i128::from(ordered(other.to_f64())))
+            .unsigned_abs();
+        distance <= u128::from(max_ulps)
+    }
+
+    /// Decompose the `f64` approximation of the number into its sign (true if negative),
+    /// mantissa and exponent, so that `number ~= (-1)^sign * mantissa * 2^exponent`.
+    /// Mirrors `integer_decode` of the `num` crate, numbers which cannot be represented in `f64`
+    /// exactly (like `1/3`) are decomposed after rounding to the nearest `f64`
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::ONE.decompose(), (false, 1 << 52, -52));
+    ///     assert_eq!(Number::new(-3, 4)?.decompose(), (true, 3 << 51, -53));
+    ///
+    ///     let x = Number::new(1234567, 1024)?;
+    ///     let (negative, mantissa, exponent) = x.decompose();
+    ///     assert!(!negative);
+    ///     assert_eq!(exponent, -42);
+    ///     assert_eq!(Number::from(mantissa).div(1_u64 << -exponent)?, x);
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn mul(&self, other: impl Into<Self>) -> Result<Self> {
-        Ok(Self {
-            inner: self.inner * other.into().inner,
-        })
+    pub fn decompose(&self) -> (bool, u64, i16) {
+        let bits = self.to_f64().to_bits();
+        let negative = bits >> 63 == 1;
+        let exponent = ((bits >> 52) & 0x7ff) as i16;
+        let fraction = bits & 0xf_ffff_ffff_ffff;
+
+        let mantissa = if exponent == 0 {
+            fraction << 1
+        } else {
+            fraction | 0x10_0000_0000_0000
+        };
+
+        (negative, mantissa, exponent - (1023 + 52))
     }
 
-    /// Divide two numbers
+    /// Check whether the number is neither infinite nor NaN
     ///
-    /// # Error
-    /// Return Error::DivisionZero if `other` is 0
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert!(Number::from(-7).is_finite());
+    ///     assert!(Number::new(1, 3)?.is_finite());
+    ///     assert!(!Number::from(f64::INFINITY).is_finite());
+    ///     assert!(!Number::from(f64::NAN).is_finite());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        !self.is_nan() && !self.is_infinite()
+    }
+
+    /// Check whether the number is NaN
     ///
     /// ```
     /// # use math::Number;
     ///
     /// # fn main() -> math::Result<()> {
-    ///     let random = Number::random();
-    ///     assert!(random.div(Number::ZERO).is_err());
-    ///     assert_eq!(Number::ZERO.div(random)?, Number::ZERO);
-    ///     assert_eq!(random.div(random)?, Number::ONE);
+    ///     assert!(Number::from(f64::NAN).is_nan());
+    ///     assert!(!Number::from(5).is_nan());
+    ///     assert!(!Number::new(1, 3)?.is_nan());
+    ///     assert!(!Number::from(f64::INFINITY).is_nan());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.inner.is_nan()
+    }
+
+    /// Check whether the number is positive or negative infinity
     ///
-    ///     let a = Number::from(3);
-    ///     let b = Number::new(3, 2)?;
-    ///     let neg_b = Number::new(-3, 2)?;
-    ///     assert_eq!(a.div(b)?, Number::from(2));
-    ///     assert_eq!(a.div(neg_b)?, Number::from(-2));
-    /// #   Ok(())
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert!(Number::from(f64::INFINITY).is_infinite());
+    ///     assert!(Number::from(f64::NEG_INFINITY).is_infinite());
+    ///     assert!(!Number::from(u64::MAX).is_infinite());
+    ///     assert!(!Number::new(1, 3)?.is_infinite());
+    ///     assert!(!Number::from(f64::NAN).is_infinite());
+    /// #     Ok(())
     /// # }
     /// ```
-    pub fn div(&self, other: impl Into<Self>) -> Result<Self> {
-        let other = other.into();
+    pub fn is_infinite(&self) -> bool {
+        self.inner.is_infinite()
+    }
 
-        if other == Self::ZERO {
-            return Err(Error::DivisionZero);
+    /// Check whether the number is a whole number
+    ///
+    /// ```
+    /// # use math::Number;
+    /// assert!(Number::from(-4).is_integer());
+    /// assert!(Number::new(6, 3).unwrap().is_integer());
+    /// assert!(!Number::new(1, 2).unwrap().is_integer());
+    /// ```
+    pub fn is_integer(&self) -> bool {
+        match (self.inner.numer(), self.inner.denom()) {
+            (Some(n), Some(d)) => n % d == 0,
+            _ => false,
         }
+    }
 
-        Ok(Self {
-            inner: self.inner / other.inner,
+    /// Get the value of an integer number
+    ///
+    /// # Error
+    /// Error::NotInteger if the number is not a whole number
+    pub(crate) fn to_i128(self) -> Result<i128> {
+        let value = match (self.inner.numer(), self.inner.denom()) {
+            (Some(n), Some(d)) if n % d == 0 => (n / d) as i128,
+            _ => return Err(Error::NotInteger),
+        };
+
+        Ok(if self.inner.is_sign_negative() {
+            -value
+        } else {
+            value
         })
     }
 
-    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    /// Convert the number to `usize`, e.g. to use it as an index
+    ///
+    /// # Error
+    /// Error::NaN if the number is not a number
+    /// Error::NegativeNumber if the number is negative
+    /// Error::NotInteger if the number is not a whole number
+    /// Error::OutOfRange if the number does not fit into `usize`
     ///
     /// ```
     /// # use math::Number;
-    /// assert_eq!(Number::random().pow(Number::ZERO), Ok(Number::ONE));
-    /// assert_eq!(Number::from(5).pow(2), Ok(Number::from(25)));
+    /// use math::error::Error;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::from(42).try_as_usize()?, 42);
+    ///     assert_eq!(Number::new(8, 4)?.try_as_usize()?, 2);
+    ///     assert_eq!(Number::ZERO.try_as_usize()?, 0);
+    ///     assert_eq!(Number::from(-1).try_as_usize(), Err(Error::NegativeNumber));
+    ///     assert_eq!(Number::new(1, 2)?.try_as_usize(), Err(Error::NotInteger));
+    ///     assert_eq!(Number::from(f64::INFINITY).try_as_usize(), Err(Error::OutOfRange));
+    ///     assert_eq!(Number::from(f64::NAN).try_as_usize(), Err(Error::NaN));
+    /// #     Ok(())
+    /// # }
     /// ```
-    pub fn power(&self, exp: impl Into<Self>) -> Result<Self> {
-        todo!()
+    pub fn try_as_usize(&self) -> Result<usize> {
+        if self.is_nan() {
+            return Err(Error::NaN);
+        }
+        if *self < Self::ZERO {
+            return Err(Error::NegativeNumber);
+        }
+        if self.is_infinite() {
+            return Err(Error::OutOfRange);
+        }
+
+        usize::try_from(self.to_i128()?).map_err(|_| Error::OutOfRange)
     }
 
-    /// Get the remainder of `self / other`
+    /// Find the closest fraction `(numerator, denominator)` whose denominator
+    /// is at most `max_denominator`, using continued fractions
+    ///
+    /// The sign is carried by the numerator, the denominator is always positive
+    ///
+    /// # Error
+    /// Error::NaN if the number is not a number
+    /// Error::OutOfRange if the number is infinite, `max_denominator` is 0
+    /// or the numerator does not fit into `i64`
     ///
     /// ```
     /// # use math::Number;
-    /// assert_eq!(Number::from(5).modulo(2), Ok(Number::ONE));
-    /// assert_eq!(Number::from(-5).modulo(2), Ok(Number::ONE));
-    /// assert_eq!(Number::from(5).modulo(-2), Number::ONE.mul(-1));
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::new(333333, 1000000)?.to_rational_approx(100)?, (1, 3));
+    ///     assert_eq!(Number::new(314159, 100000)?.to_rational_approx(1000)?, (355, 113));
+    ///     assert_eq!(Number::new(-314159, 100000)?.to_rational_approx(10)?, (-22, 7));
+    ///     assert_eq!(Number::from(0.5).to_rational_approx(100)?, (1, 2));
+    ///     assert_eq!(Number::from(7).to_rational_approx(1)?, (7, 1));
+    ///
+    ///     // Unreduced fractions
+    ///     assert_eq!(Number::new(2, 4)?.to_rational_approx(100)?, (1, 2));
+    ///     assert_eq!(Number::new(30, 10)?.to_rational_approx(5)?, (3, 1));
+    ///     assert_eq!(Number::new(0, 5)?.to_rational_approx(3)?, (0, 1));
+    ///     assert_eq!(Number::new_unchecked(-6, 9).to_rational_approx(2)?, (-1, 2));
+    ///     assert!(Number::ONE.to_rational_approx(0).is_err());
+    ///     assert!(Number::from(f64::NAN).to_rational_approx(10).is_err());
+    /// #     Ok(())
+    /// # }
     /// ```
-    pub fn modulo(&self, other: impl Into<Self>) -> Result<Self> {
-        todo!()
+    pub fn to_rational_approx(&self, max_denominator: u64) -> Result<(i64, i64)> {
+        if self.is_nan() {
+            return Err(Error::NaN);
+        }
+        let (Some(&numer), Some(&denom)) = (self.inner.numer(), self.inner.denom()) else {
+            return Err(Error::OutOfRange);
+        };
+        if max_denominator == 0 {
+            return Err(Error::OutOfRange);
+        }
+
+        let (p, q) = best_fraction(numer, denom, u64::MAX, max_denominator);
+
+        let p = i64::try_from(p).map_err(|_| Error::OutOfRange)?;
+        let q = i64::try_from(q).map_err(|_| Error::OutOfRange)?;
+        Ok((if self.inner.is_sign_negative() { -p } else { p }, q))
     }
 
-    /// Get the absolute value of the given number
+    /// Approximate the number as `f64`, used for transcendental functions
+    fn to_f64(self) -> f64 {
+        self.inner.to_f64().unwrap_or(f64::NAN)
+    }
+
+    /// Convert the `f64` result of a transcendental function back to a number
     ///
-    /// ```
-    /// # use math::Number;
-    /// let a = Number::random();
-    /// let neg_a = a.mul(-1);
+    /// The exact binary value of an `f64` has a huge denominator, so any further arithmetic
+    /// would overflow. The value is replaced by the closest fraction whose numerator and
+    /// denominator both fit into `u32`, so the product of two such results still fits into `u64`.
+    /// Values of at least `2^32` are rounded to an integer instead
     ///
-    /// assert_eq!(a.abs(), Ok(a));
-    /// assert_eq!(neg_a.abs(), Ok(a));
+    /// # Error
+    /// Error::NaN if the value is not a number
+    /// Error::Overflow if the value is infinite or does not fit into the number
+    fn try_from_f64(value: f64) -> Result<Self> {
+        if value.is_nan() {
+            return Err(Error::NaN);
+        }
+        if value.abs() >= u64::MAX as f64 {
+            return Err(Error::Overflow);
+        }
+
+        // value = mantissa * 2^exponent
+        let bits = value.abs().to_bits();
+        let (mut mantissa, mut exponent) = match (bits >> 52) as i32 {
+            0 => (bits, -1074),
+            biased => (bits & ((1 << 52) - 1) | (1 << 52), biased - 1075),
+        };
+
+        let (numer, denom) = if exponent >= 0 {
+            (mantissa << exponent, 1)
+        } else {
+            // Bits below 2^-63 are far beyond the precision of the result
+            if exponent < -63 {
+                mantissa = mantissa.checked_shr((-63 - exponent) as u32).unwrap_or(0);
+                exponent = -63;
+            }
+            (mantissa, 1 << -exponent)
+        };
+
+        let (numer, denom) = if value.abs() < (1_u64 << 32) as f64 {
+            best_fraction(numer, denom, u32::MAX.into(), u32::MAX.into())
+        } else {
+            best_fraction(numer, denom, u64::MAX, 1)
+        };
+
+        let inner = if value.is_sign_negative() && numer != 0 {
+            GenericFraction::new_neg(numer, denom)
+        } else {
+            GenericFraction::new(numer, denom)
+        };
+
+        Ok(Self { inner })
+    }
+}
+
+impl Number {
+    /// Generate a random number in range of <0, 1>
+    ///
+    /// ```
+    /// # use math::Number;
+    /// assert!(Number::random() != Number::random());
+    /// assert!(Number::random() >= Number::ZERO);
+    /// assert!(Number::random() <= Number::ONE);
+    /// ```
+    pub fn random() -> Self {
+        use rand::prelude::*;
+
+        let mut rng = rand::thread_rng();
+        let denom: u64 = rng.gen_range(1..(u64::MAX / 2));
+        let num: u64 = rng.gen_range(0..=denom);
+
+        Self::new_unchecked(num as _, denom as _)
+    }
+
+    /// Add two numbers together
+    ///
+    /// # Error
+    /// Error::Overflow if the result does not fit, see `Number::checked_add`
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    /// let a = Number::new(1, 10)?;
+    /// let b = Number::new(2, 10)?;
+    ///
+    /// assert_eq!(a.add(b)?, Number::new(3, 10)?);
+    /// assert_eq!(b.add(a), a.add(b));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn add(&self, other: impl Into<Self>) -> Result<Self> {
+        self.checked_add(other).ok_or(Error::Overflow)
+    }
+
+    /// Same as `Number::add`, but return None if the result does not fit
+    ///
+    /// ```
+    /// # use math::Number;
+    /// assert_eq!(Number::from(2).checked_add(3), Some(Number::from(5)));
+    /// assert_eq!(Number::from(u64::MAX).checked_add(1), None);
+    /// assert_eq!(Number::from(u64::MAX).checked_add(-1), Some(Number::from(u64::MAX - 1)));
+    /// ```
+    pub fn checked_add(&self, other: impl Into<Self>) -> Option<Self> {
+        let inner = self.inner.checked_add(&other.into().inner)?;
+        Some(Self { inner })
+    }
+
+    /// Subtract two numbers
+    ///
+    /// # Error
+    /// Error::Overflow if the result does not fit, see `Number::checked_sub`
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let a = Number::new(1, 10)?;
+    ///     let b = Number::new(2, 10)?;
+    ///
+    ///     assert_eq!(a.sub(b)?, Number::new(-1, 10)?);
+    ///     assert_eq!(b.sub(a)?, Number::new(1, 10)?);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn sub(&self, other: impl Into<Self>) -> Result<Self> {
+        self.checked_sub(other).ok_or(Error::Overflow)
+    }
+
+    /// Same as `Number::sub`, but return None if the result does not fit
+    ///
+    /// ```
+    /// # use math::Number;
+    /// assert_eq!(Number::from(2).checked_sub(3), Some(Number::from(-1)));
+    /// assert_eq!(Number::from(u64::MAX).mul(-1).unwrap().checked_sub(1), None);
+    /// ```
+    pub fn checked_sub(&self, other: impl Into<Self>) -> Option<Self> {
+        let inner = self.inner.checked_sub(&other.into().inner)?;
+        Some(Self { inner })
+    }
+
+    /// Multiply two numbers
+    ///
+    /// # Error
+    /// Error::Overflow if the result does not fit, see `Number::checked_mul`
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let a = Number::new(1, 10)?;
+    ///     let b = Number::new(2, 10)?;
+    ///
+    ///     assert_eq!(a.mul(b)?, Number::new(2, 100)?);
+    ///     assert_eq!(b.mul(a), a.mul(b));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn mul(&self, other: impl Into<Self>) -> Result<Self> {
+        self.checked_mul(other).ok_or(Error::Overflow)
+    }
+
+    /// Same as `Number::mul`, but return None if the result does not fit
+    ///
+    /// ```
+    /// # use math::Number;
+    /// assert_eq!(Number::from(-4).checked_mul(3), Some(Number::from(-12)));
+    /// assert_eq!(Number::from(u64::MAX).checked_mul(2), None);
+    /// assert_eq!(Number::new(1, i64::MAX).unwrap().checked_mul(Number::new(1, 3).unwrap()), None);
+    /// ```
+    pub fn checked_mul(&self, other: impl Into<Self>) -> Option<Self> {
+        let inner = self.inner.checked_mul(&other.into().inner)?;
+        Some(Self { inner })
+    }
+
+    /// Divide two numbers
+    ///
+    /// # Error
+    /// Return Error::DivisionZero if `other` is 0
+    /// Return Error::Overflow if the result does not fit, see `Number::checked_div`
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let random = Number::random();
+    ///     assert!(random.div(Number::ZERO).is_err());
+    ///     assert_eq!(Number::ZERO.div(random)?, Number::ZERO);
+    ///     assert_eq!(random.div(random)?, Number::ONE);
+    ///
+    ///     let a = Number::from(3);
+    ///     let b = Number::new(3, 2)?;
+    ///     let neg_b = Number::new(-3, 2)?;
+    ///     assert_eq!(a.div(b)?, Number::from(2));
+    ///     assert_eq!(a.div(neg_b)?, Number::from(-2));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn div(&self, other: impl Into<Self>) -> Result<Self> {
+        let other = other.into();
+
+        if other.is_zero() {
+            return Err(Error::DivisionZero);
+        }
+
+        self.checked_div(other).ok_or(Error::Overflow)
+    }
+
+    /// Same as `Number::div`, but return None if `other` is 0 or the result does not fit
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::from(3).checked_div(Number::new(3, 2)?), Some(Number::from(2)));
+    ///     assert_eq!(Number::from(3).checked_div(0), None);
+    ///     assert_eq!(Number::from(u64::MAX).checked_div(Number::new(1, 3)?), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn checked_div(&self, other: impl Into<Self>) -> Option<Self> {
+        let other = other.into();
+        if other.is_zero() {
+            return None;
+        }
+
+        let inner = self.inner.checked_div(&other.inner)?;
+        Some(Self { inner })
+    }
+
+    /// Divide two numbers and round the quotient toward negative infinity
+    ///
+    /// # Error
+    /// Return Error::DivisionZero if `other` is 0
+    /// Return Error::Overflow if the quotient does not fit
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert!(Number::from(5).int_div(0).is_err());
+    ///     assert!(Number::from(u64::MAX).int_div(Number::new(1, 3)?).is_err());
+    ///     assert_eq!(Number::from(7).int_div(2)?, Number::from(3));
+    ///     assert_eq!(Number::from(-7).int_div(2)?, Number::from(-4));
+    ///     assert_eq!(Number::from(7).int_div(-2)?, Number::from(-4));
+    ///     assert_eq!(Number::from(6).int_div(2)?, Number::from(3));
+    ///     assert_eq!(Number::new(15, 2)?.int_div(Number::new(1, 2)?)?, Number::from(15));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn int_div(&self, other: impl Into<Self>) -> Result<Self> {
+        Ok(Self {
+            inner: self.div(other)?.inner.floor(),
+        })
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    /// Integer exponents are computed exactly. A fractional exponent `p/q` is computed
+    /// as `root(self, q)^p`, so perfect powers stay exact and odd roots of negative numbers are real.
+    /// Otherwise the result is computed through `f64`
+    ///
+    /// # Error
+    /// Error::DivisionZero if the number is 0 and `exp` is negative
+    /// Error::NegativeRoot if the number is negative and `exp` is not a fraction with an odd denominator
+    /// Error::Overflow if the result does not fit, see `Number::checked_pow`
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::random().power(Number::ZERO)?, Number::ONE);
+    ///     assert_eq!(Number::new(1, 3)?.power(0)?, Number::ONE);
+    ///     assert_eq!(Number::from(5).power(2)?, Number::from(25));
+    ///     assert_eq!(Number::from(-2).power(3)?, Number::from(-8));
+    ///     assert_eq!(Number::from(2).power(-2)?, Number::new(1, 4)?);
+    ///     assert_eq!(Number::new(2, 3)?.power(3)?, Number::new(8, 27)?);
+    ///     assert_eq!(Number::from(4).power(Number::new(1, 2)?)?, Number::from(2));
+    ///     assert!(Number::ZERO.power(-1).is_err());
+    ///     assert!(Number::from(-4).power(Number::new(1, 2)?).is_err());
+    ///
+    ///     assert_eq!(Number::from(-8).power(Number::new(1, 3)?)?, Number::from(-2));
+    ///     assert_eq!(Number::from(-8).power(Number::new(2, 3)?)?, Number::from(4));
+    ///     assert_eq!(Number::from(8).power(Number::new(2, 3)?)?, Number::from(4));
+    ///     assert_eq!(Number::from(8).power(Number::new(-2, 3)?)?, Number::new(1, 4)?);
+    ///     assert_eq!(Number::from(-32).power(Number::new(3, 5)?)?, Number::from(-8));
+    ///     assert_eq!(Number::from(-8).power(Number::new(2, 6)?)?, Number::from(-2));
+    ///
+    ///     let tolerance = Number::new(1, 1_000_000_000)?;
+    ///     assert!(Number::from(2).power(Number::new(3, 2)?)?.within(2_f64.powf(1.5), tolerance));
+    ///     assert!(Number::from(-2).power(Number::new(4, 3)?)?.within(2_f64.powf(4.0 / 3.0), tolerance));
+    ///     assert!(Number::from(2).power(64).is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn power(&self, exp: impl Into<Self>) -> Result<Self> {
+        let exp = exp.into();
+
+        let Ok(n) = exp.to_i128() else {
+            return self.fraction_power(exp);
+        };
+
+        let mut base = if n < 0 { Self::ONE.div(*self)? } else { *self };
+        let mut n = n.unsigned_abs();
+        let mut result = Self::ONE;
+
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.mul(base)?;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.mul(base)?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Raise self to a non-integer power `p/q` as `root(self, q)^p`
+    fn fraction_power(&self, exp: Self) -> Result<Self> {
+        let negative = self.inner.is_sign_negative() && !self.is_zero();
+
+        let (Some(&p), Some(&q)) = (exp.inner.numer(), exp.inner.denom()) else {
+            if negative {
+                return Err(Error::NegativeRoot);
+            }
+            return Self::try_from_f64(self.to_f64().powf(exp.to_f64()));
+        };
+
+        let divisor = gcd(p, q);
+        let (p, q) = (p / divisor, q / divisor);
+        let p_signed = Self {
+            inner: if exp.inner.is_sign_negative() {
+                GenericFraction::new_neg(p, 1_u64)
+            } else {
+                GenericFraction::new(p, 1_u64)
+            },
+        };
+
+        match self.root(q)?.power(p_signed) {
+            // The root is inexact and its power does not fit, compute the result directly instead
+            Err(Error::Overflow) => {
+                let magnitude = self.to_f64().abs().powf(exp.to_f64());
+                Self::try_from_f64(if negative && p % 2 == 1 {
+                    -magnitude
+                } else {
+                    magnitude
+                })
+            }
+            result => result,
+        }
+    }
+
+    /// Same as `Number::power`, but return None instead of an error
+    ///
+    /// ```
+    /// # use math::Number;
+    /// assert_eq!(Number::from(2).checked_pow(10), Some(Number::from(1024)));
+    /// assert_eq!(Number::from(2).checked_pow(63), Some(Number::from(1_u64 << 63)));
+    /// assert_eq!(Number::from(2).checked_pow(64), None);
+    /// assert_eq!(Number::ZERO.checked_pow(-1), None);
+    /// ```
+    pub fn checked_pow(&self, exp: impl Into<Self>) -> Option<Self> {
+        self.power(exp).ok()
+    }
+
+    /// Computes `self^exp mod modulus` using square-and-multiply,
+    /// so the intermediate power never overflows.
+    /// The result is always in the range <0, modulus)
+    ///
+    /// # Error
+    /// Error::NotInteger if any of the arguments is not an integer
+    /// Error::DivisionZero if `modulus` is 0
+    /// Error::OutOfRange if `exp` or `modulus` is negative
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert!(Number::from(2).pow_mod(10, 0).is_err());
+    ///     assert!(Number::from(2).pow_mod(Number::new(1, 2)?, 7).is_err());
+    ///     assert!(Number::from(2).pow_mod(-1, 7).is_err());
+    ///
+    ///     assert_eq!(Number::from(2).pow_mod(10, 1000)?, Number::from(24));
+    ///     assert_eq!(Number::from(-2).pow_mod(3, 5)?, Number::from(2));
+    ///     assert_eq!(Number::from(5).pow_mod(0, 1)?, Number::ZERO);
+    ///     // 123456789^987654321 is far too large to be computed directly
+    ///     assert_eq!(
+    ///         Number::from(123456789).pow_mod(987654321, 1_000_000_007)?,
+    ///         Number::from(652541198)
+    ///     );
+    ///     assert_eq!(
+    ///         Number::from(i64::MAX).pow_mod(i64::MAX, 18446744073709551557_u64)?,
+    ///         Number::from(4727312973194949781_u64)
+    ///     );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn pow_mod(&self, exp: impl Into<Self>, modulus: impl Into<Self>) -> Result<Self> {
+        let base = self.to_i128()?;
+        let mut exp = exp.into().to_i128()?;
+        let modulus = modulus.into().to_i128()?;
+
+        if modulus == 0 {
+            return Err(Error::DivisionZero);
+        }
+        if modulus < 0 || exp < 0 {
+            return Err(Error::OutOfRange);
+        }
+
+        // Both factors are below `modulus`, which fits in u64, so the product fits in u128
+        let modulus = modulus as u128;
+        let mut base = base.rem_euclid(modulus as i128) as u128;
+        let mut result = 1 % modulus;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base % modulus;
+            }
+            base = base * base % modulus;
+            exp >>= 1;
+        }
+
+        Ok(Self::from(result as u64))
+    }
+
+    /// Get the remainder of `self / other`
+    /// The remainder has the sign of `other`, matching `Number::int_div`
+    ///
+    /// # Error
+    /// Return Error::DivisionZero if `other` is 0
+    /// Return Error::Overflow if the quotient does not fit
+    ///
+    /// ```
+    /// # use math::Number;
+    /// assert_eq!(Number::from(5).modulo(2), Ok(Number::ONE));
+    /// assert!(Number::from(u64::MAX).modulo(Number::new(1, 3).unwrap()).is_err());
+    /// assert_eq!(Number::from(-5).modulo(2), Ok(Number::ONE));
+    /// assert_eq!(Number::from(5).modulo(-2), Number::ONE.mul(-1));
+    /// ```
+    pub fn modulo(&self, other: impl Into<Self>) -> Result<Self> {
+        Ok(self.div_rem(other)?.1)
+    }
+
+    /// Get both the quotient rounded toward negative infinity and the remainder of `self / other`,
+    /// so that `q * other + r == self`
+    ///
+    /// # Error
+    /// Return Error::DivisionZero if `other` is 0
+    /// Return Error::Overflow if the quotient does not fit
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert!(Number::from(7).div_rem(0).is_err());
+    ///     assert!(Number::from(u64::MAX).div_rem(Number::new(1, 3)?).is_err());
+    ///     assert_eq!(Number::from(7).div_rem(2)?, (Number::from(3), Number::ONE));
+    ///     assert_eq!(Number::from(-7).div_rem(2)?, (Number::from(-4), Number::ONE));
+    ///     assert_eq!(Number::from(7).div_rem(-2)?, (Number::from(-4), Number::from(-1)));
+    ///
+    ///     let a = Number::new(-23, 4)?;
+    ///     let b = Number::new(3, 2)?;
+    ///     let (q, r) = a.div_rem(b)?;
+    ///     assert_eq!(q.mul(b)?.add(r)?, a);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn div_rem(&self, other: impl Into<Self>) -> Result<(Self, Self)> {
+        let other = other.into();
+        let quotient = self.int_div(other)?;
+        let remainder = self.sub(quotient.mul(other)?)?;
+
+        Ok((quotient, remainder))
+    }
+
+    /// Get the absolute value of the given number
+    ///
+    /// ```
+    /// # use math::Number;
+    /// let a = Number::random();
+    /// let neg_a = a.mul(-1).unwrap();
+    ///
+    /// assert_eq!(a.abs(), Ok(a));
+    /// assert_eq!(neg_a.abs(), Ok(a));
+    /// ```
+    pub fn abs(&self) -> Result<Self> {
+        Ok(Self {
+            inner: self.inner.abs(),
+        })
+    }
+
+    /// Get the sign of the number: -1 if negative, 0 if zero and 1 if positive
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::new(-5, 2)?.signum()?, Number::from(-1));
+    ///     assert_eq!(Number::ZERO.signum()?, Number::ZERO);
+    ///     assert_eq!(Number::new(1, 1000)?.signum()?, Number::ONE);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn signum(&self) -> Result<Self> {
+        if self.is_zero() {
+            return Ok(Self::ZERO);
+        }
+
+        Ok(Self {
+            inner: self.inner.signum(),
+        })
+    }
+
+    /// Get the fractional part of the number, it has the same sign as the number
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::new(5, 2)?.fract()?, Number::new(1, 2)?);
+    ///     assert_eq!(Number::new(-5, 2)?.fract()?, Number::new(-1, 2)?);
+    ///     assert_eq!(Number::from(3).fract()?, Number::ZERO);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn fract(&self) -> Result<Self> {
+        self.sub(self.trunc()?)
+    }
+
+    /// Check whether the distance between `self` and `other` is at most `tol`
+    ///
+    /// If the exact distance does not fit into the number, it is compared as `f64` instead.
+    /// Always false if any of the numbers or the distance is not a number
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert!(Number::from(100).within(101, 2));
+    ///     assert!(Number::from(101).within(100, 1));
+    ///     assert!(!Number::from(100).within(103, 2));
+    ///     assert!(!Number::from(100).within(100, -1));
+    ///
+    ///     // The exact distances overflow
+    ///     assert!(Number::new(1, 3)?.within(Number::new(1, i64::MAX)?, 1));
+    ///     assert!(!Number::new(1, 3)?.within(Number::new(1, i64::MAX)?, Number::new(1, 10)?));
+    ///     assert!(!Number::from(u64::MAX).within(Number::from(u64::MAX).mul(-1)?, 1));
+    ///
+    ///     let nan = Number::from(f64::NAN);
+    ///     let inf = Number::from(f64::INFINITY);
+    ///     assert!(!nan.within(0, 1));
+    ///     assert!(!nan.within(nan, 0));
+    ///     assert!(!Number::ZERO.within(0, nan));
+    ///     assert!(!inf.within(inf, 0));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn within(&self, other: impl Into<Self>, tol: impl Into<Self>) -> bool {
+        let other = other.into();
+        let tol = tol.into();
+
+        if self.is_nan() || other.is_nan() || tol.is_nan() {
+            return false;
+        }
+
+        match self.checked_sub(other) {
+            Some(distance) if distance.is_nan() => false,
+            Some(distance) => distance.inner.abs() <= tol.inner,
+            None => (self.to_f64() - other.to_f64()).abs() <= tol.to_f64(),
+        }
+    }
+
+    /// Get the change from `self` to `other` in percent of `self`
+    ///
+    /// # Error
+    /// Return Error::DivisionZero if `self` is 0
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::from(100).pct_diff(110)?, Number::from(10));
+    ///     assert_eq!(Number::from(200).pct_diff(150)?, Number::from(-25));
+    ///     assert!(Number::ZERO.pct_diff(5).is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn pct_diff(&self, other: impl Into<Self>) -> Result<Self> {
+        other.into().sub(*self)?.div(*self)?.mul(100)
+    }
+
+    /// Round the number to `decimals` decimal places using the given rounding `mode`
+    ///
+    /// # Error
+    /// Error::Overflow if the scaled number does not fit
+    ///
+    /// ```
+    /// # use math::Number;
+    /// use math::number::RoundingMode;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let a = Number::new(5, 2)?;
+    ///     let b = Number::new(7, 2)?;
+    ///     let neg = Number::new(-5, 2)?;
+    ///
+    ///     assert_eq!(a.round(0, RoundingMode::HalfEven)?, Number::from(2));
+    ///     assert_eq!(b.round(0, RoundingMode::HalfEven)?, Number::from(4));
+    ///     assert_eq!(a.round(0, RoundingMode::HalfUp)?, Number::from(3));
+    ///     assert_eq!(b.round(0, RoundingMode::HalfUp)?, Number::from(4));
+    ///
+    ///     assert_eq!(neg.round(0, Default::default())?, Number::from(-3));
+    ///     assert_eq!(neg.round(0, RoundingMode::HalfUp)?, Number::from(-2));
+    ///     assert_eq!(neg.round(0, RoundingMode::TowardZero)?, Number::from(-2));
+    ///     assert_eq!(neg.round(0, RoundingMode::Floor)?, Number::from(-3));
+    ///     assert_eq!(a.round(0, RoundingMode::Ceiling)?, Number::from(3));
+    ///
+    ///     let x = Number::new(125, 1000)?;
+    ///     assert_eq!(x.round(2, RoundingMode::HalfEven)?, Number::new(12, 100)?);
+    ///     assert_eq!(x.round(2, RoundingMode::HalfAwayFromZero)?, Number::new(13, 100)?);
+    ///     assert_eq!(Number::PI.round(4, Default::default())?, Number::new(31416, 10000)?);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn round(&self, decimals: u8, mode: RoundingMode) -> Result<Self> {
+        let scale = 10_u64
+            .checked_pow(u32::from(decimals))
+            .map(Self::from)
+            .ok_or(Error::Overflow)?;
+
+        let value = Self {
+            inner: self
+                .inner
+                .checked_mul(&scale.inner)
+                .ok_or(Error::Overflow)?,
+        };
+
+        let lower = Self {
+            inner: value.inner.floor(),
+        };
+        let upper = lower.add(1)?;
+        let diff = value.sub(lower)?;
+        let half = Self::new_unchecked(1, 2);
+
+        let rounded = match mode {
+            _ if diff.is_zero() => lower,
+            RoundingMode::Floor => lower,
+            RoundingMode::Ceiling => upper,
+            RoundingMode::TowardZero if value > Self::ZERO => lower,
+            RoundingMode::TowardZero => upper,
+            _ if diff < half => lower,
+            _ if diff > half => upper,
+            RoundingMode::HalfUp => upper,
+            RoundingMode::HalfAwayFromZero if value > Self::ZERO => upper,
+            RoundingMode::HalfAwayFromZero => lower,
+            RoundingMode::HalfEven if lower.modulo(2)?.is_zero() => lower,
+            RoundingMode::HalfEven => upper,
+        };
+
+        rounded.div(scale)
+    }
+
+    /// Get the integer part of the number, rounding toward zero
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let x = Number::new(29, 10)?.trunc()?;
+    ///     assert_eq!(x, Number::from(2));
+    ///     assert!(x.is_integer());
+    ///     assert_eq!(Number::new(-29, 10)?.trunc()?, Number::from(-2));
+    ///     assert_eq!(Number::from(7).trunc()?, Number::from(7));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn trunc(&self) -> Result<Self> {
+        Ok(Self {
+            inner: self.inner.trunc(),
+        })
+    }
+
+    /// Get the smaller of two numbers
+    ///
+    /// # Error
+    /// Error::NaN if either number is not a number
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::from(2).nan_safe_min(Number::new(7, 2)?)?, Number::from(2));
+    ///     assert_eq!(Number::from(-2).nan_safe_min(-3)?, Number::from(-3));
+    ///     assert!(Number::from(2).nan_safe_min(f64::NAN).is_err());
+    ///     assert!(Number::from(f64::NAN).nan_safe_min(2).is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn nan_safe_min(&self, other: impl Into<Self>) -> Result<Self> {
+        let other = other.into();
+
+        if self.is_nan() || other.is_nan() {
+            return Err(Error::NaN);
+        }
+
+        Ok(Ord::min(*self, other))
+    }
+
+    /// Get the greater of two numbers
+    ///
+    /// # Error
+    /// Error::NaN if either number is not a number
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::from(2).nan_safe_max(Number::new(7, 2)?)?, Number::new(7, 2)?);
+    ///     assert_eq!(Number::from(-2).nan_safe_max(-3)?, Number::from(-2));
+    ///     assert!(Number::from(2).nan_safe_max(f64::NAN).is_err());
+    ///     assert!(Number::from(f64::NAN).nan_safe_max(2).is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn nan_safe_max(&self, other: impl Into<Self>) -> Result<Self> {
+        let other = other.into();
+
+        if self.is_nan() || other.is_nan() {
+            return Err(Error::NaN);
+        }
+
+        Ok(Ord::max(*self, other))
+    }
+
+    /// Restrict the number to the range <lo, hi>
+    ///
+    /// # Error
+    /// Error::NaN if the number or either bound is not a number
+    /// Error::InvalidRange if `lo` is greater than `hi`
+    ///
+    /// ```
+    /// # use math::Number;
+    /// use math::error::Error;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::from(15).try_clamp(0, 10)?, Number::from(10));
+    ///     assert_eq!(Number::from(-5).try_clamp(0, 10)?, Number::ZERO);
+    ///     assert_eq!(Number::new(1, 2)?.try_clamp(0, 10)?, Number::new(1, 2)?);
+    ///     assert_eq!(Number::from(3).try_clamp(3, 3)?, Number::from(3));
+    ///     assert_eq!(Number::from(5).try_clamp(10, 0), Err(Error::InvalidRange));
+    ///     assert_eq!(Number::from(f64::NAN).try_clamp(0, 1), Err(Error::NaN));
+    ///     assert_eq!(Number::from(5).try_clamp(f64::NAN, 10), Err(Error::NaN));
+    ///
+    ///     // Works the same through a reference
+    ///     let x = Number::from(5);
+    ///     assert_eq!((&x).try_clamp(10, 0), Err(Error::InvalidRange));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn try_clamp(&self, lo: impl Into<Self>, hi: impl Into<Self>) -> Result<Self> {
+        let lo = lo.into();
+        let hi = hi.into();
+
+        if self.is_nan() || lo.is_nan() || hi.is_nan() {
+            return Err(Error::NaN);
+        }
+        if lo > hi {
+            return Err(Error::InvalidRange);
+        }
+
+        Ok(Ord::min(Ord::max(*self, lo), hi))
+    }
+
+    /// Check whether the number is in the range <lo, hi>
+    /// Always false if `lo` is greater than `hi`
+    ///
+    /// ```
+    /// # use math::Number;
+    /// assert!(Number::from(5).is_in_range(0, 10));
+    /// assert!(Number::from(10).is_in_range(0, 10));
+    /// assert!(!Number::from(11).is_in_range(0, 10));
+    /// assert!(!Number::from(5).is_in_range(10, 0));
+    /// ```
+    pub fn is_in_range(&self, lo: impl Into<Self>, hi: impl Into<Self>) -> bool {
+        lo.into() <= *self && *self <= hi.into()
+    }
+
+    /// Calculate factorial of a given number
+    /// The number is not limited to integer, it can be a fraction
+    ///
+    /// # Error
+    /// return Error::NaN if the number is not a number
+    /// return Error::FactorialNegative if the number is less than 0
+    /// return Error::Overflow if the result is too large
+    ///
+    /// Integers are computed exactly, fractions through the gamma function `x! = Γ(x + 1)`
+    ///
+    /// ```
+    /// # use math::number::{Number, Radix};
+    /// use math::error::Error;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::ZERO.factorial()?, Number::ONE);
+    ///     assert_eq!(Number::from(5).factorial()?, Number::from(120));
+    ///     assert_eq!(Number::from(20).factorial()?, Number::from(2432902008176640000_u64));
+    ///     assert!(Number::from(21).factorial().is_err());
+    ///     assert_eq!(Number::new(32, 10)?.factorial()?.to_string(Radix::Dec, 5), "7.75668");
+    ///     assert_eq!(Number::new(1, 2)?.factorial()?.to_string(Radix::Dec, 6), "0.886226");
+    ///     assert!(Number::new(-1, 2)?.factorial().is_err());
+    ///     assert_eq!(Number::from(-1).factorial(), Err(Error::FactorialNegative));
+    ///     assert_eq!(Number::from(f64::NAN).factorial(), Err(Error::NaN));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn factorial(&self) -> Result<Self> {
+        if self.is_nan() {
+            return Err(Error::NaN);
+        }
+        if *self < Self::ZERO {
+            return Err(Error::FactorialNegative);
+        }
+
+        if let Ok(n) = self.to_i128() {
+            let mut result: u64 = 1;
+            for i in 2..=n {
+                result = u64::try_from(i)
+                    .ok()
+                    .and_then(|i| result.checked_mul(i))
+                    .ok_or(Error::Overflow)?;
+            }
+
+            return Ok(Self::from(result));
+        }
+
+        Self::try_from_f64(ln_gamma(self.to_f64() + 1.0).exp())
+    }
+
+    /// Same as `Number::factorial`, but return None instead of an error
+    ///
+    /// ```
+    /// # use math::Number;
+    /// assert_eq!(Number::from(5).checked_factorial(), Some(Number::from(120)));
+    /// assert_eq!(Number::from(-1).checked_factorial(), None);
+    /// assert_eq!(Number::from(100).checked_factorial(), None);
+    ///
+    /// let sum = (0..5)
+    ///     .filter_map(|n| Number::from(n).checked_factorial())
+    ///     .fold(Number::ZERO, |acc, x| acc.add(x).unwrap());
+    /// assert_eq!(sum, Number::from(34));
+    /// ```
+    pub fn checked_factorial(&self) -> Option<Self> {
+        self.factorial().ok()
+    }
+
+    /// Computes the natural logarithm of the gamma function, `ln(Γ(x))`.
+    /// It is computed directly, so it stays finite long after `Γ(x)` itself would overflow
+    ///
+    /// # Error
+    /// Error::NaN if the number is not a number
+    /// Error::OutOfRange if the number is not greater than 0
+    /// Error::Overflow if the result does not fit
+    ///
+    /// ```
+    /// # use math::Number;
+    /// use math::error::Error;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let tolerance = Number::new(1, 1_000_000_000)?;
+    ///     assert!(Number::ONE.ln_gamma()?.within(0, tolerance));
+    ///     assert!(Number::from(5).ln_gamma()?.within(24_f64.ln(), tolerance));
+    ///     assert!(Number::new(1, 4)?.ln_gamma()?.within(1.2880225246980774, tolerance));
+    ///     assert!(Number::ZERO.ln_gamma().is_err());
+    ///     assert!(Number::from(-3).ln_gamma().is_err());
+    ///     // ln(Γ(10^18)) is about 4 * 10^19, more than u64 can hold
+    ///     assert!(Number::from(10_u64.pow(18)).ln_gamma().is_err());
+    ///     assert_eq!(Number::from(f64::NAN).ln_gamma(), Err(Error::NaN));
+    /// #     Ok(())
+    /// # }
     /// ```
-    pub fn abs(&self) -> Result<Self> {
-        todo!()
+    pub fn ln_gamma(&self) -> Result<Self> {
+        if self.is_nan() {
+            return Err(Error::NaN);
+        }
+        if *self <= Self::ZERO {
+            return Err(Error::OutOfRange);
+        }
+
+        Self::try_from_f64(ln_gamma(self.to_f64()))
     }
 
-    /// Calculate factorial of a given number
-    /// The number is not limited to integer, it can be a fraction
+    /// Computes the natural logarithm of the factorial, `ln(x!) = ln(Γ(x + 1))`,
+    /// without computing the factorial itself
     ///
     /// # Error
-    /// return Error::FactorialNegative if the number is less than 0
+    /// Error::NaN if the number is not a number
+    /// Error::FactorialNegative if the number is less than 0
+    /// Error::Overflow if the result does not fit
     ///
     /// ```
     /// # use math::Number;
-    /// assert_eq!(Number::Zero.factorial(), Number::One);
-    /// assert_eq!(Number::from(5).factorial(), Ok(Number::from(120)));
-    /// assert_eq!(Number::new(32, 10).factorial().unwrap().to_string(6), "7.75669");
-    /// assert!(Number::from(-1).factorial().is_err());
+    /// use math::error::Error;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let tolerance = Number::new(1, 1_000_000_000)?;
+    ///     assert!(Number::from(5).log_factorial()?.within(120_f64.ln(), tolerance));
+    ///     assert!(Number::ZERO.log_factorial()?.within(0, tolerance));
+    ///     // 1000! itself is far too large
+    ///     assert!(Number::from(1000).factorial().is_err());
+    ///     assert!(Number::from(1000).log_factorial()?.within(5912.128178488163, tolerance));
+    ///     assert_eq!(Number::from(-1).log_factorial(), Err(Error::FactorialNegative));
+    ///     assert_eq!(Number::from(f64::NAN).log_factorial(), Err(Error::NaN));
+    /// #     Ok(())
+    /// # }
     /// ```
-    pub fn factorial(&self) -> Result<Self> {
-        todo!()
+    pub fn log_factorial(&self) -> Result<Self> {
+        if self.is_nan() {
+            return Err(Error::NaN);
+        }
+        if *self < Self::ZERO {
+            return Err(Error::FactorialNegative);
+        }
+
+        Self::try_from_f64(ln_gamma(self.to_f64() + 1.0))
     }
 
     /// Returns the logarithm of the number with respect to an arbitrary `base`.
@@ -335,7 +1733,10 @@ impl Number {
     ///
     /// # Error
     /// Error::ZeroNthRoot if the `nth` is 0
-    /// Error::NegativeRoot if the number is negative
+    /// Error::NegativeRoot if the number is negative and `nth` is not an odd integer
+    /// Error::Overflow if the result does not fit into the number
+    ///
+    /// Odd roots of negative numbers are real, perfect powers give exact results
     ///
     /// ```
     /// # use math::Number;
@@ -347,28 +1748,89 @@ impl Number {
     ///     assert!(Number::from(-1).root(3).is_ok());
     ///     assert!(Number::from(-1).root(87).is_ok());
     ///
-    ///     let a = Number::random();
-    ///     let b = Number::random();
-    ///     let nth = Number::random();
-    ///
-    ///     // root(a^nth) == a
-    ///     assert_er!(a.pow(nth)?.root(nth), a);
-    ///     // root(ab) == root(a) * root(b)
-    ///     assert_eq!(a.mul(b)?.root(nth), a.root(nth)?.mul(b.root(nth)?));
-    ///     // root(a/b) == root(a) / root(b)
-    ///     assert_eq!(a.div(b)?.root(nth), a.root(nth)?.div(b.root(nth)?));
-    ///
+    ///     let big = Number::from((1_u64 << 60) + 12345);
+    ///     assert_eq!(big.root(1)?, big);
+    ///     assert_eq!(Number::from(u64::MAX).root(1)?, Number::from(u64::MAX));
+    ///     assert_eq!(Number::from(4294967295_u64 * 4294967295).root(2)?, Number::from(4294967295_u64));
+    ///     assert_eq!(Number::from(2642245_u64.pow(3)).root(3)?, Number::from(2642245));
+    ///
+    ///     assert_eq!(Number::from(-27).root(3)?, Number::from(-3));
+    ///     assert_eq!(Number::from(27).root(3)?, Number::from(3));
+    ///     assert_eq!(Number::from(16).root(4)?, Number::from(2));
+    ///     assert_eq!(Number::new(-1, 32)?.root(5)?, Number::new(-1, 2)?);
+    ///     assert_eq!(Number::from(4).root(-2)?, Number::new(1, 2)?);
+    ///     assert!(Number::from(-16).root(4).is_err());
+    ///     assert!(Number::from(-8).root(Number::new(3, 2)?).is_err());
+    ///     // The square of 10^10 does not fit
+    ///     assert!(Number::from(10_000_000_000_u64).root(Number::new(1, 2)?).is_err());
     /// #     Ok(())
     /// # }
     /// ```
     pub fn root(&self, nth: impl Into<Self>) -> Result<Self> {
-        todo!()
+        let nth = nth.into();
+
+        if nth.is_zero() {
+            return Err(Error::ZeroNthRoot);
+        }
+        if nth.is_one() {
+            return Ok(*self);
+        }
+
+        if self.inner.is_sign_negative() && !self.is_zero() {
+            let odd = nth.to_i128().is_ok_and(|n| n % 2 != 0);
+            if !odd {
+                return Err(Error::NegativeRoot);
+            }
+
+            let root = Self {
+                inner: self.inner.abs(),
+            }
+            .root(nth)?;
+
+            return Ok(Self { inner: -root.inner });
+        }
+
+        if let Ok(n) = nth.to_i128() {
+            if n < 0 {
+                return Self::ONE.div(self.root(-n)?);
+            }
+
+            let exact = u32::try_from(n).ok().and_then(|n| {
+                let num = exact_root(*self.inner.numer()?, n)?;
+                let denom = exact_root(*self.inner.denom()?, n)?;
+                Some(GenericFraction::new(num, denom))
+            });
+
+            if let Some(inner) = exact {
+                return Ok(Self { inner });
+            }
+        }
+
+        Self::try_from_f64(self.to_f64().powf(1.0 / nth.to_f64()))
     }
 
     /// Returns the square root of a number.
     /// This function is the same as `root` with the `nth` of 2
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::from(9).sqrt()?, Number::from(3));
+    ///     assert_eq!(Number::new(1, 4)?.sqrt()?, Number::new(1, 2)?);
+    ///     assert!(Number::from(-4).sqrt().is_err());
+    ///
+    ///     // Inexact roots are approximated closely enough to keep calculating with them
+    ///     let tolerance = Number::new(1, 1_000_000_000)?;
+    ///     let sqrt2 = Number::from(2).sqrt()?;
+    ///     assert!(sqrt2.within(std::f64::consts::SQRT_2, tolerance));
+    ///     assert!(sqrt2.mul(sqrt2)?.within(2, tolerance));
+    ///     assert!(sqrt2.power(2)?.within(2, tolerance));
+    /// #     Ok(())
+    /// # }
+    /// ```
     pub fn sqrt(&self) -> Result<Self> {
-        todo!()
+        self.root(2)
     }
 
     /// Computes the sine of a number (in radians).
@@ -531,37 +1993,395 @@ impl Number {
         todo!()
     }
 
+    /// Computes the hyperbolic sine of a number.
+    ///
+    /// # Error
+    /// Error::Overflow if the result does not fit into the number
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::ZERO.sinh()?, Number::ZERO);
+    ///     assert_eq!(Number::from(-2).sinh()?, Number::from(2).sinh()?.mul(-1)?);
+    ///     assert!(Number::from(40).sinh()?.is_finite());
+    ///     assert!(Number::from(50).sinh().is_err());
+    ///     assert!(Number::from(-50).sinh().is_err());
+    ///     assert!(Number::from(800).sinh().is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn sinh(&self) -> Result<Self> {
+        Self::try_from_f64(self.to_f64().sinh())
+    }
+
+    /// Computes the hyperbolic cosine of a number.
+    ///
+    /// # Error
+    /// Error::Overflow if the result does not fit into the number
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::ZERO.cosh()?, Number::ONE);
+    ///     let tolerance = Number::new(1, 1_000_000_000)?;
+    ///     assert!(Number::ONE.cosh()?.power(2)?.within(1_f64.cosh().powi(2), tolerance));
+    ///     assert_eq!(Number::from(-2).cosh()?, Number::from(2).cosh()?);
+    ///     assert!(Number::from(50).cosh().is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn cosh(&self) -> Result<Self> {
+        Self::try_from_f64(self.to_f64().cosh())
+    }
+
+    /// Computes the hyperbolic tangent of a number.
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::ZERO.tanh()?, Number::ZERO);
+    ///     assert!(Number::from(100).tanh()? <= Number::ONE);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn tanh(&self) -> Result<Self> {
+        Self::try_from_f64(self.to_f64().tanh())
+    }
+
+    /// Computes the inverse hyperbolic sine of a number.
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::ZERO.asinh()?, Number::ZERO);
+    ///     assert!(Number::from(u64::MAX).asinh()? < Number::from(50));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn asinh(&self) -> Result<Self> {
+        Self::try_from_f64(self.to_f64().asinh())
+    }
+
+    /// Computes the inverse hyperbolic cosine of a number.
+    ///
+    /// # Error
+    /// Error::NaN if the number is not a number
+    /// Error::OutOfRange if the number is less than 1
+    ///
+    /// ```
+    /// # use math::Number;
+    /// use math::error::Error;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert!(Number::ZERO.acosh().is_err());
+    ///     assert!(Number::new(99, 100)?.acosh().is_err());
+    ///     assert_eq!(Number::ONE.acosh()?, Number::ZERO);
+    ///     assert_eq!(Number::from(f64::NAN).acosh(), Err(Error::NaN));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn acosh(&self) -> Result<Self> {
+        if self.is_nan() {
+            return Err(Error::NaN);
+        }
+        if *self < Self::ONE {
+            return Err(Error::OutOfRange);
+        }
+
+        Self::try_from_f64(self.to_f64().acosh())
+    }
+
+    /// Computes the inverse hyperbolic tangent of a number.
+    ///
+    /// # Error
+    /// Error::NaN if the number is not a number
+    /// Error::OutOfRange if the number is not in the range (-1, 1)
+    ///
+    /// ```
+    /// # use math::Number;
+    /// use math::error::Error;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert!(Number::ONE.atanh().is_err());
+    ///     assert!(Number::from(-1).atanh().is_err());
+    ///     assert!(Number::from(5).atanh().is_err());
+    ///     assert_eq!(Number::ZERO.atanh()?, Number::ZERO);
+    ///     assert_eq!(Number::from(f64::NAN).atanh(), Err(Error::NaN));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn atanh(&self) -> Result<Self> {
+        if self.is_nan() {
+            return Err(Error::NaN);
+        }
+        if *self <= Number::from(-1) || *self >= Self::ONE {
+            return Err(Error::OutOfRange);
+        }
+
+        Self::try_from_f64(self.to_f64().atanh())
+    }
+
+    /// Computes `sqrt(self^2 + other^2)` without overflowing the intermediate squares
+    ///
+    /// # Error
+    /// Error::Overflow if the result does not fit into the number
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::from(3).hypot(4)?, Number::from(5));
+    ///     assert_eq!(Number::from(-3).hypot(-4)?, Number::from(5));
+    ///
+    ///     // Squaring these directly would overflow
+    ///     let a = Number::from(3_000_000_000_i64);
+    ///     let b = Number::from(4_000_000_000_i64);
+    ///     assert_eq!(a.hypot(b)?, Number::from(5_000_000_000_i64));
+    ///     assert!(Number::from(u64::MAX).hypot(u64::MAX).is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn hypot(&self, other: impl Into<Self>) -> Result<Self> {
+        Self::try_from_f64(self.to_f64().hypot(other.into().to_f64()))
+    }
+
+    /// Returns a number with the magnitude of `self` and the sign of `sign`
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert_eq!(Number::from(3).copysign(-1)?, Number::from(-3));
+    ///     assert_eq!(Number::from(-3).copysign(2)?, Number::from(3));
+    ///     assert_eq!(Number::from(-3).copysign(Number::ZERO)?, Number::from(3));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn copysign(&self, sign: impl Into<Self>) -> Result<Self> {
+        let abs = self.inner.abs();
+
+        Ok(Self {
+            inner: if sign.into().inner.is_sign_negative() {
+                -abs
+            } else {
+                abs
+            },
+        })
+    }
+
+    /// Get the remainder of `self / other` truncated toward zero.
+    /// Unlike `Number::modulo`, the result has the sign of `self`
+    ///
+    /// # Error
+    /// Return Error::DivisionZero if `other` is 0
+    /// Return Error::Overflow if the intermediate quotient does not fit
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert!(Number::from(5).fmod(0).is_err());
+    ///     assert!(Number::new(1, 3)?.fmod(Number::new(1, i64::MAX)?).is_err());
+    ///     assert_eq!(Number::from(7).fmod(3)?, Number::ONE);
+    ///     assert_eq!(Number::from(-7).fmod(3)?, Number::from(-1));
+    ///     assert_eq!(Number::new(11, 2)?.fmod(2)?, Number::new(3, 2)?);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn fmod(&self, other: impl Into<Self>) -> Result<Self> {
+        let other = other.into();
+
+        if other.is_zero() {
+            return Err(Error::DivisionZero);
+        }
+
+        let quotient = self.div(other)?.trunc()?;
+        self.sub(quotient.mul(other)?)
+    }
+
+    /// Computes the four quadrant arctangent of `self` (y) and `x` in radians.
+    /// Return value is in the range <-pi, pi>
+    ///
+    /// ```
+    /// # use math::Number;
+    /// use std::f64::consts::{FRAC_PI_2, PI};
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let tolerance = Number::new(1, 1_000_000_000)?;
+    ///     assert_eq!(Number::ZERO.atan2(1)?, Number::ZERO);
+    ///     assert!(Number::ONE.atan2(0)?.within(FRAC_PI_2, tolerance));
+    ///     assert!(Number::ZERO.atan2(-1)?.within(PI, tolerance));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn atan2(&self, x: impl Into<Self>) -> Result<Self> {
+        Self::try_from_f64(self.to_f64().atan2(x.into().to_f64()))
+    }
+
+    /// Convert polar coordinates (`r`, `theta` in radians) to cartesian coordinates `(x, y)`
+    ///
+    /// Like `Number::combination`, neither argument is the center of the function
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let (x, y) = Number::polar(2, 0)?;
+    ///     assert_eq!((x, y), (Number::from(2), Number::ZERO));
+    ///
+    ///     let tolerance = Number::new(1, 1_000_000_000)?;
+    ///     let (x, y) = Number::polar(2, Number::from(std::f64::consts::FRAC_PI_2))?;
+    ///     assert!(x.within(0, tolerance));
+    ///     assert!(y.within(2, tolerance));
+    ///
+    ///     let (x, y) = Number::polar(1, Number::new(1, 3)?)?;
+    ///     assert!(x.mul(x)?.within((1_f64 / 3.0).cos().powi(2), tolerance));
+    ///     assert!(x.mul(y)?.within((2_f64 / 3.0).sin() / 2.0, tolerance));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn polar(r: impl Into<Self>, theta: impl Into<Self>) -> Result<(Self, Self)> {
+        let r = r.into().to_f64();
+        let (sin, cos) = theta.into().to_f64().sin_cos();
+
+        Ok((Self::try_from_f64(r * cos)?, Self::try_from_f64(r * sin)?))
+    }
+
+    /// Convert cartesian coordinates (`x`, `y`) to polar coordinates `(r, theta)`,
+    /// `theta` is in radians in the range <-pi, pi>
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     let tolerance = Number::new(1, 1_000_000_000)?;
+    ///     assert_eq!(Number::cartesian(3, 4)?.0, Number::from(5));
+    ///     assert!(Number::cartesian(-1, 0)?.1.within(std::f64::consts::PI, tolerance));
+    ///
+    ///     // Round trip
+    ///     let (r, theta) = (Number::new(5, 2)?, Number::new(-3, 4)?);
+    ///     let (x, y) = Number::polar(r, theta)?;
+    ///     let (r2, theta2) = Number::cartesian(x, y)?;
+    ///     assert!(r2.within(r, tolerance));
+    ///     assert!(theta2.within(theta, tolerance));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn cartesian(x: impl Into<Self>, y: impl Into<Self>) -> Result<(Self, Self)> {
+        let x = x.into();
+        let y = y.into();
+
+        Ok((x.hypot(y)?, y.atan2(x)?))
+    }
+
     /// Calculate combination number of the given `n` and `k`
     ///
     /// Since combination number is defined as `C(n, k)` mathematically
     /// Neither `n` nor `k` is considered the center of the function
     /// that's why this function does not taking `self` as parameter like other functions
     ///
+    /// The multiplicative formula is used, so no intermediate factorial can overflow
+    ///
     /// # Error
     /// Error::FactorialNegative if either `n` or `k` is negative, because they will need to be factorialized
+    /// Error::NotInteger if either `n` or `k` is not an integer
+    /// Error::OutOfRange if `k` is greater than `n`
+    /// Error::Overflow if the result does not fit
     ///
     /// ```
     /// # use math::Number;
     ///
     /// # fn main() -> math::Result<()> {
-    ///     assert!(Number::combination(-1, Number::random()).is_err());
-    ///     assert!(Number::combination(Number::random(), -1).is_err());
+    ///     assert!(Number::combination(-1, 2).is_err());
+    ///     assert!(Number::combination(5, -1).is_err());
+    ///     assert!(Number::combination(Number::new(5, 2)?, 1).is_err());
+    ///     assert!(Number::combination(3, 4).is_err());
     ///
-    ///     let n = Number::random();
+    ///     let n = Number::from(7);
     ///
-    ///     // if k > n => C(n, k) == 0
-    ///     assert_eq!(Number::combination(3, 4)?, Number::ZERO);
+    ///     assert_eq!(Number::combination(5, 2)?, Number::from(10));
     ///     // C(n, 0) == 1
     ///     assert_eq!(Number::combination(n, 0)?, Number::ONE);
     ///     // C(n, 1) == n
     ///     assert_eq!(Number::combination(n, 1)?, n);
     ///     // C(n, n) == 1
     ///     assert_eq!(Number::combination(n, n)?, Number::ONE);
+    ///     // 100! alone would overflow
+    ///     assert_eq!(Number::combination(100, 3)?, Number::from(161700));
     /// #     Ok(())
     /// # }
     /// ```
     pub fn combination(n: impl Into<Self>, k: impl Into<Self>) -> Result<Self> {
-        todo!()
+        let (n, k) = Self::choose_args(n.into(), k.into())?;
+        let k = k.min(n - k);
+
+        // After step `i` the value is C(n - k + i, i), so the division is always exact
+        let mut result: u128 = 1;
+        for i in 1..=k {
+            result = result
+                .checked_mul(n - k + i)
+                .ok_or(Error::Overflow)?
+                / i;
+        }
+
+        u64::try_from(result)
+            .map(Self::from)
+            .map_err(|_| Error::Overflow)
+    }
+
+    /// Calculate the number of `k` permutations of `n` elements, `P(n, k) = n! / (n - k)!`
+    ///
+    /// # Error
+    /// Error::FactorialNegative if either `n` or `k` is negative
+    /// Error::NotInteger if either `n` or `k` is not an integer
+    /// Error::OutOfRange if `k` is greater than `n`
+    /// Error::Overflow if the result does not fit
+    ///
+    /// ```
+    /// # use math::Number;
+    ///
+    /// # fn main() -> math::Result<()> {
+    ///     assert!(Number::permutation(-1, 2).is_err());
+    ///     assert!(Number::permutation(2, 3).is_err());
+    ///     assert!(Number::permutation(100, 50).is_err());
+    ///
+    ///     assert_eq!(Number::permutation(5, 2)?, Number::from(20));
+    ///     assert_eq!(Number::permutation(5, 0)?, Number::ONE);
+    ///     assert_eq!(Number::permutation(5, 5)?, Number::from(120));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn permutation(n: impl Into<Self>, k: impl Into<Self>) -> Result<Self> {
+        let (n, k) = Self::choose_args(n.into(), k.into())?;
+
+        let mut result: u64 = 1;
+        for i in 0..k {
+            result = u64::try_from(n - i)
+                .ok()
+                .and_then(|factor| result.checked_mul(factor))
+                .ok_or(Error::Overflow)?;
+        }
+
+        Ok(Self::from(result))
+    }
+
+    /// Validate the arguments of `combination` and `permutation`
+    fn choose_args(n: Self, k: Self) -> Result<(u128, u128)> {
+        let n = n.to_i128()?;
+        let k = k.to_i128()?;
+
+        if n < 0 || k < 0 {
+            return Err(Error::FactorialNegative);
+        }
+        if k > n {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok((n as u128, k as u128))
     }
 }
 
@@ -586,3 +2406,145 @@ impl PartialEq for Number {
 }
 
 impl Eq for Number {}
+
+/// Hash consistent with `Eq`: equal fractions hash equally even if they are not reduced,
+/// `0` and `-0` hash the same and all NaN values hash the same
+///
+/// ```
+/// # use math::Number;
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+///
+/// fn hash(n: Number) -> u64 {
+///     let mut hasher = DefaultHasher::new();
+///     n.hash(&mut hasher);
+///     hasher.finish()
+/// }
+///
+/// # fn main() -> math::Result<()> {
+///     assert_eq!(hash(Number::new(30, 10)?), hash(Number::new(3, 1)?));
+///     assert_eq!(hash(Number::from(-0.0)), hash(Number::ZERO));
+///     assert_eq!(hash(Number::new(0, -5)?), hash(Number::ZERO));
+///     assert_eq!(hash(Number::from(f64::NAN)), hash(Number::from(f64::NAN)));
+///     assert_ne!(hash(Number::new(1, 3)?), hash(Number::new(-1, 3)?));
+///     assert_ne!(hash(Number::new(1, 3)?), hash(Number::new(3, 1)?));
+/// #     Ok(())
+/// # }
+/// ```
+impl Hash for Number {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+/// Coefficients of the Lanczos approximation with `g = 7`
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Natural logarithm of the gamma function for `x > 0`, using the Lanczos approximation
+fn ln_gamma(x: f64) -> f64 {
+    use std::f64::consts::PI;
+
+    if x < 0.5 {
+        // Reflection formula, the approximation is only accurate for x >= 0.5
+        return PI.ln() - (PI * x).sin().ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let a = LANCZOS[1..]
+        .iter()
+        .enumerate()
+        .fold(LANCZOS[0], |a, (i, p)| a + p / (x + i as f64 + 1.0));
+
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + a.ln()
+}
+
+/// Get the fraction closest to `numer / denom` with a numerator of at most `max_numer`
+/// and a denominator of at most `max_denom`, using continued fractions.
+/// The result is reduced, `max_denom` must not be 0
+fn best_fraction(numer: u64, denom: u64, max_numer: u64, max_denom: u64) -> (u64, u64) {
+    // Fractions are not always stored reduced, e.g. by `Number::new_unchecked`
+    let divisor = gcd(numer, denom);
+    let (numer, denom) = (numer / divisor, denom / divisor);
+    if numer <= max_numer && denom <= max_denom {
+        return (numer, denom);
+    }
+
+    let (max_p, max_q) = (max_numer as u128, max_denom as u128);
+    let (numer, denom) = (numer as u128, denom as u128);
+
+    // Convergents p0/q0 and p1/q1 of the continued fraction of numer/denom
+    let (mut p0, mut q0, mut p1, mut q1) = (0_u128, 1_u128, 1_u128, 0_u128);
+    let (mut n, mut d) = (numer, denom);
+    while d != 0 {
+        let a = n / d;
+        let (p2, q2) = (p0 + a * p1, q0 + a * q1);
+        if p2 > max_p || q2 > max_q {
+            break;
+        }
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+        (n, d) = (d, n - a * d);
+    }
+
+    // Best semiconvergent against the last convergent that fits
+    let mut k = (max_q - q0) / q1;
+    if let Some(kp) = (max_p - p0).checked_div(p1) {
+        k = k.min(kp);
+    }
+    let (sp, sq) = (p0 + k * p1, q0 + k * q1);
+    let distance = |p: u128, q: u128| (p * denom).abs_diff(numer * q) as f64 / q as f64;
+    let (p, q) = if distance(p1, q1) <= distance(sp, sq) {
+        (p1, q1)
+    } else {
+        (sp, sq)
+    };
+
+    (p as u64, q as u64)
+}
+
+/// Greatest common divisor of `a` and `b`, `gcd(0, 0)` is 1 so it is always safe to divide by
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a.max(1)
+}
+
+/// Get the integer `n`th root of `value` if `value` is a perfect `n`th power
+fn exact_root(value: u64, n: u32) -> Option<u64> {
+    if n == 1 {
+        return Some(value);
+    }
+
+    let fits = |r: u64| r.checked_pow(n).is_some_and(|p| p <= value);
+
+    // The f64 guess can be off by more than one for large values,
+    // so correct it until `root^n <= value < (root + 1)^n`
+    let mut root = (value as f64).powf(1.0 / f64::from(n)) as u64;
+    while root > 0 && !fits(root) {
+        root -= 1;
+    }
+    while fits(root + 1) {
+        root += 1;
+    }
+
+    (root.checked_pow(n) == Some(value)).then_some(root)
+}
+
+/// Get the uppercase character of a single digit in the given base
+fn digit_char(digit: u128, base: u128) -> char {
+    char::from_digit(digit as u32, base as u32)
+        .unwrap_or('?')
+        .to_ascii_uppercase()
+}